@@ -1,14 +1,15 @@
 use std::convert::TryFrom;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use thiserror::Error;
 
 #[derive(Debug, PartialEq)]
-enum QueryResponse {
+pub enum QueryResponse {
     Query = 0,
     Response = 1,
 }
 
 #[derive(Debug, PartialEq)]
-enum Opcode {
+pub enum Opcode {
     Query = 0,
     IQuery = 1,
     Status = 2,
@@ -19,7 +20,7 @@ enum Opcode {
 }
 
 #[derive(Debug, PartialEq)]
-enum RCode {
+pub enum RCode {
     FormErr = 1,
     ServFail = 2,
     NXDomain = 3,
@@ -34,49 +35,177 @@ enum RCode {
     Unassigned,
 }
 
-#[derive(Debug)]
-struct DnsPacket {
-    packet_identifier: u16,
-    query_response: QueryResponse,
-    operation_code: Opcode,
-    authoritative_answer: bool,
-    truncated_message: bool,
-    recursion_desired: bool,
-    recursion_available: bool,
-    response_code: Result<(), RCode>,
-    question_count: u16,
-    answer_count: u16,
-    authority_count: u16,
-    additional_count: u16,
-    questions: Vec<Question>,
-    answers: Vec<RecordPreamble>,
+#[derive(Debug, PartialEq)]
+pub struct DnsPacket {
+    pub packet_identifier: u16,
+    pub query_response: QueryResponse,
+    pub operation_code: Opcode,
+    pub authoritative_answer: bool,
+    pub truncated_message: bool,
+    pub recursion_desired: bool,
+    pub recursion_available: bool,
+    pub response_code: Result<(), RCode>,
+    pub question_count: u16,
+    pub answer_count: u16,
+    pub authority_count: u16,
+    pub additional_count: u16,
+    pub questions: Vec<Question>,
+    pub answers: Vec<RecordPreamble>,
+    pub authorities: Vec<RecordPreamble>,
+    pub additionals: Vec<RecordPreamble>,
+    pub edns: Option<Edns>,
 }
 
 #[derive(Debug, PartialEq)]
-enum RecordType {
-    AddressRecord,
-    Other,
+pub struct Edns {
+    pub udp_payload_size: u16,
+    pub extended_rcode: u8,
+    pub version: u8,
+    pub dnssec_ok: bool,
+    pub options: Vec<(u16, Vec<u8>)>,
+}
+
+impl TryFrom<&RecordPreamble> for Edns {
+    type Error = DnsPacketParseError;
+
+    fn try_from(preamble: &RecordPreamble) -> Result<Self, Self::Error> {
+        let udp_payload_size = preamble.question.class.to_u16();
+        let extended_rcode = ((preamble.time_to_live >> 24) & 0xff) as u8;
+        let version = ((preamble.time_to_live >> 16) & 0xff) as u8;
+        let dnssec_ok = (preamble.time_to_live >> 15) & 1 == 1;
+        let rdata: &[u8] = match &preamble.data {
+            RecordData::Unknown(bytes) => bytes,
+            _ => &[],
+        };
+
+        let mut options = Vec::new();
+        let mut offset = 0usize;
+        while offset < rdata.len() {
+            let option_code = get16(offset, rdata)?;
+            let option_length = get16(offset + 2, rdata)? as usize;
+            let start = offset + 4;
+            let end = start + option_length;
+            let data = rdata
+                .get(start..end)
+                .ok_or_else(|| DnsPacketParseError::OutOfBounds {
+                    index: end,
+                    length: rdata.len(),
+                })?;
+            options.push((option_code, data.to_vec()));
+            offset = end;
+        }
+
+        Ok(Self {
+            udp_payload_size,
+            extended_rcode,
+            version,
+            dnssec_ok,
+            options,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RecordType {
+    A,
+    Ns,
+    Cname,
+    Soa,
+    Ptr,
+    Mx,
+    Txt,
+    Aaaa,
+    Srv,
+    Opt,
+    Unknown(u16),
+}
+
+impl From<u16> for RecordType {
+    fn from(value: u16) -> Self {
+        match value {
+            1 => RecordType::A,
+            2 => RecordType::Ns,
+            5 => RecordType::Cname,
+            6 => RecordType::Soa,
+            12 => RecordType::Ptr,
+            15 => RecordType::Mx,
+            16 => RecordType::Txt,
+            28 => RecordType::Aaaa,
+            33 => RecordType::Srv,
+            41 => RecordType::Opt,
+            other => RecordType::Unknown(other),
+        }
+    }
+}
+
+impl RecordType {
+    pub fn to_u16(&self) -> u16 {
+        match self {
+            RecordType::A => 1,
+            RecordType::Ns => 2,
+            RecordType::Cname => 5,
+            RecordType::Soa => 6,
+            RecordType::Ptr => 12,
+            RecordType::Mx => 15,
+            RecordType::Txt => 16,
+            RecordType::Aaaa => 28,
+            RecordType::Srv => 33,
+            RecordType::Opt => 41,
+            RecordType::Unknown(value) => *value,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
-enum Class {
+pub enum Class {
     Internet,
-    Other,
+    Chaos,
+    Hesiod,
+    Any,
+    Unknown(u16),
+}
+
+impl From<u16> for Class {
+    fn from(value: u16) -> Self {
+        match value {
+            1 => Class::Internet,
+            3 => Class::Chaos,
+            4 => Class::Hesiod,
+            255 => Class::Any,
+            other => Class::Unknown(other),
+        }
+    }
+}
+
+impl Class {
+    pub fn to_u16(&self) -> u16 {
+        match self {
+            Class::Internet => 1,
+            Class::Chaos => 3,
+            Class::Hesiod => 4,
+            Class::Any => 255,
+            Class::Unknown(value) => *value,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
-struct Question {
-    name: String,
-    r#type: RecordType,
-    class: Class,
+pub struct Question {
+    pub name: String,
+    pub r#type: RecordType,
+    pub class: Class,
 }
 
 #[derive(Error, Debug, PartialEq)]
-enum DnsPacketParseError {
+pub enum DnsPacketParseError {
     #[error("byte index {index:?} was out of bounds (length: {length:?})")]
     OutOfBounds { index: usize, length: usize },
     #[error("too many jumps reading label")]
     JumpLimitExceeded,
+    #[error("compression pointer at {pointer:?} does not point to an earlier offset")]
+    InvalidPointer { pointer: u16 },
+    #[error("name exceeds 255 octets")]
+    NameTooLong,
 }
 
 struct LabelSequenceIterator<'a, 'b> {
@@ -84,6 +213,7 @@ struct LabelSequenceIterator<'a, 'b> {
     packet: &'a [u8],
     global_position: &'b mut u16,
     jump_counter: u8,
+    name_length: u16,
 }
 
 impl<'a, 'b> LabelSequenceIterator<'a, 'b> {
@@ -93,15 +223,21 @@ impl<'a, 'b> LabelSequenceIterator<'a, 'b> {
             packet,
             global_position: position,
             jump_counter: 0,
+            name_length: 0,
         }
     }
 
     fn read_section(&mut self) -> Result<Option<&'a [u8]>, DnsPacketParseError> {
         while get8(self.position as usize, self.packet)? & 0b11000000 == 0b11000000 {
+            let pointer = self.position;
             if self.jump_counter == 0 {
                 *self.global_position += 2;
             }
-            self.position = get16(self.position as usize, self.packet)? & 0b0011_1111_1111_1111;
+            let target = get16(self.position as usize, self.packet)? & 0b0011_1111_1111_1111;
+            if target >= pointer {
+                return Err(DnsPacketParseError::InvalidPointer { pointer });
+            }
+            self.position = target;
             self.jump_counter += 1;
             if self.jump_counter > 5 {
                 return Err(DnsPacketParseError::JumpLimitExceeded);
@@ -114,6 +250,10 @@ impl<'a, 'b> LabelSequenceIterator<'a, 'b> {
         if length == 0 {
             return Ok(None);
         }
+        self.name_length += 1 + length as u16;
+        if self.name_length > 255 {
+            return Err(DnsPacketParseError::NameTooLong);
+        }
         let old_position = self.position as usize + 1;
         let new_position = old_position + length as usize;
         let content = self.packet.get(old_position..new_position).ok_or_else(|| {
@@ -136,9 +276,158 @@ impl<'a, 'b> Iterator for LabelSequenceIterator<'a, 'b> {
 }
 
 #[derive(Debug, PartialEq)]
-struct RecordPreamble {
-    question: Question,
-    time_to_live: u32,
+pub enum RecordData {
+    A(Ipv4Addr),
+    Aaaa(Ipv6Addr),
+    Cname(String),
+    Ns(String),
+    Ptr(String),
+    Mx {
+        preference: u16,
+        exchange: String,
+    },
+    Soa {
+        mname: String,
+        rname: String,
+        serial: u32,
+        refresh: u32,
+        retry: u32,
+        expire: u32,
+        minimum: u32,
+    },
+    Txt(Vec<Vec<u8>>),
+    Unknown(Vec<u8>),
+}
+
+impl RecordData {
+    fn parse(
+        type_code: u16,
+        rdata_start: u16,
+        rdata: &[u8],
+        packet: &[u8],
+    ) -> Result<Self, DnsPacketParseError> {
+        Ok(match type_code {
+            1 if rdata.len() == 4 => {
+                RecordData::A(Ipv4Addr::new(rdata[0], rdata[1], rdata[2], rdata[3]))
+            }
+            28 if rdata.len() == 16 => {
+                let mut octets = [0u8; 16];
+                octets.copy_from_slice(rdata);
+                RecordData::Aaaa(Ipv6Addr::from(octets))
+            }
+            2 => {
+                let mut position = rdata_start;
+                RecordData::Ns(read_name(&mut position, packet)?)
+            }
+            5 => {
+                let mut position = rdata_start;
+                RecordData::Cname(read_name(&mut position, packet)?)
+            }
+            12 => {
+                let mut position = rdata_start;
+                RecordData::Ptr(read_name(&mut position, packet)?)
+            }
+            15 => {
+                let preference = get16(rdata_start as usize, packet)?;
+                let mut exchange_position = rdata_start + 2;
+                let exchange = read_name(&mut exchange_position, packet)?;
+                RecordData::Mx {
+                    preference,
+                    exchange,
+                }
+            }
+            6 => {
+                let mut position = rdata_start;
+                let mname = read_name(&mut position, packet)?;
+                let rname = read_name(&mut position, packet)?;
+                let serial = get32(position as usize, packet)?;
+                let refresh = get32(position as usize + 4, packet)?;
+                let retry = get32(position as usize + 8, packet)?;
+                let expire = get32(position as usize + 12, packet)?;
+                let minimum = get32(position as usize + 16, packet)?;
+                RecordData::Soa {
+                    mname,
+                    rname,
+                    serial,
+                    refresh,
+                    retry,
+                    expire,
+                    minimum,
+                }
+            }
+            16 => {
+                let mut strings = Vec::new();
+                let mut offset = 0usize;
+                while offset < rdata.len() {
+                    let length = rdata[offset] as usize;
+                    let start = offset + 1;
+                    let end = start + length;
+                    let chunk = rdata
+                        .get(start..end)
+                        .ok_or_else(|| DnsPacketParseError::OutOfBounds {
+                            index: end,
+                            length: rdata.len(),
+                        })?;
+                    strings.push(chunk.to_vec());
+                    offset = end;
+                }
+                RecordData::Txt(strings)
+            }
+            _ => RecordData::Unknown(rdata.to_vec()),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            RecordData::A(address) => address.octets().to_vec(),
+            RecordData::Aaaa(address) => address.octets().to_vec(),
+            RecordData::Ns(name) | RecordData::Cname(name) | RecordData::Ptr(name) => {
+                encode_name(name)
+            }
+            RecordData::Mx {
+                preference,
+                exchange,
+            } => {
+                let mut bytes = preference.to_be_bytes().to_vec();
+                bytes.extend(encode_name(exchange));
+                bytes
+            }
+            RecordData::Soa {
+                mname,
+                rname,
+                serial,
+                refresh,
+                retry,
+                expire,
+                minimum,
+            } => {
+                let mut bytes = encode_name(mname);
+                bytes.extend(encode_name(rname));
+                bytes.extend_from_slice(&serial.to_be_bytes());
+                bytes.extend_from_slice(&refresh.to_be_bytes());
+                bytes.extend_from_slice(&retry.to_be_bytes());
+                bytes.extend_from_slice(&expire.to_be_bytes());
+                bytes.extend_from_slice(&minimum.to_be_bytes());
+                bytes
+            }
+            RecordData::Txt(strings) => {
+                let mut bytes = Vec::new();
+                for string in strings {
+                    bytes.push(string.len() as u8);
+                    bytes.extend(string);
+                }
+                bytes
+            }
+            RecordData::Unknown(data) => data.clone(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RecordPreamble {
+    pub question: Question,
+    pub time_to_live: u32,
+    pub data: RecordData,
 }
 
 fn get8(index: usize, value: &[u8]) -> Result<u8, DnsPacketParseError> {
@@ -157,6 +446,29 @@ fn get16(index: usize, value: &[u8]) -> Result<u16, DnsPacketParseError> {
     Ok((a << 8) + b)
 }
 
+fn read_name(position: &mut u16, packet: &[u8]) -> Result<String, DnsPacketParseError> {
+    let mut name = String::new();
+    for label in LabelSequenceIterator::new(position, packet) {
+        if !name.is_empty() {
+            name.push('.');
+        }
+        name.extend(label?.iter().map(|&b| char::from(b)));
+    }
+    Ok(name)
+}
+
+fn encode_name(name: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    if !name.is_empty() {
+        for label in name.split('.') {
+            bytes.push(label.len() as u8);
+            bytes.extend(label.chars().map(|c| c as u8));
+        }
+    }
+    bytes.push(0);
+    bytes
+}
+
 fn get32(index: usize, value: &[u8]) -> Result<u32, DnsPacketParseError> {
     let d = get8(index + 3, value)? as u32;
     let c = get8(index + 2, value)? as u32;
@@ -190,21 +502,7 @@ impl TryFrom<&[u8]> for DnsPacket {
         let truncated_message = (get8(2, value)? >> 1) & 1 == 1;
         let recursion_desired = get8(2, value)? & 1 == 1;
         let recursion_available = get8(3, value)? >> 7 == 1;
-        let response_code = match get8(3, value)? & 0b1111 {
-            0 => Ok(()),
-            1 => Err(RCode::FormErr),
-            2 => Err(RCode::ServFail),
-            3 => Err(RCode::NXDomain),
-            4 => Err(RCode::NotImp),
-            5 => Err(RCode::Refused),
-            6 => Err(RCode::YXDomain),
-            7 => Err(RCode::YXRRSet),
-            8 => Err(RCode::NXRRSet),
-            9 => Err(RCode::NotAuth),
-            10 => Err(RCode::NotZone),
-            11 => Err(RCode::DSOTYPENI),
-            _ => Err(RCode::Unassigned),
-        };
+        let response_code_low = get8(3, value)? & 0b1111;
 
         let question_count = get16(4, value)?;
         let answer_count = get16(6, value)?;
@@ -222,6 +520,40 @@ impl TryFrom<&[u8]> for DnsPacket {
             .map(|_| RecordPreamble::try_from((value, &mut position)))
             .collect::<Result<Vec<RecordPreamble>, Self::Error>>()?;
 
+        let authorities: Vec<RecordPreamble> = (0..authority_count)
+            .map(|_| RecordPreamble::try_from((value, &mut position)))
+            .collect::<Result<Vec<RecordPreamble>, Self::Error>>()?;
+
+        let additionals: Vec<RecordPreamble> = (0..additional_count)
+            .map(|_| RecordPreamble::try_from((value, &mut position)))
+            .collect::<Result<Vec<RecordPreamble>, Self::Error>>()?;
+
+        let edns = additionals
+            .iter()
+            .find(|record| record.question.r#type == RecordType::Opt)
+            .map(Edns::try_from)
+            .transpose()?;
+
+        let response_code_code: u16 = match &edns {
+            Some(edns) => ((edns.extended_rcode as u16) << 4) | response_code_low as u16,
+            None => response_code_low as u16,
+        };
+        let response_code = match response_code_code {
+            0 => Ok(()),
+            1 => Err(RCode::FormErr),
+            2 => Err(RCode::ServFail),
+            3 => Err(RCode::NXDomain),
+            4 => Err(RCode::NotImp),
+            5 => Err(RCode::Refused),
+            6 => Err(RCode::YXDomain),
+            7 => Err(RCode::YXRRSet),
+            8 => Err(RCode::NXRRSet),
+            9 => Err(RCode::NotAuth),
+            10 => Err(RCode::NotZone),
+            11 => Err(RCode::DSOTYPENI),
+            _ => Err(RCode::Unassigned),
+        };
+
         Ok(DnsPacket {
             packet_identifier,
             query_response,
@@ -237,29 +569,90 @@ impl TryFrom<&[u8]> for DnsPacket {
             additional_count,
             questions,
             answers,
+            authorities,
+            additionals,
+            edns,
         })
     }
 }
 
+impl DnsPacket {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.packet_identifier.to_be_bytes());
+
+        let query_response = match self.query_response {
+            QueryResponse::Query => 0,
+            QueryResponse::Response => 1,
+        };
+        let operation_code = match self.operation_code {
+            Opcode::Query => 0,
+            Opcode::IQuery => 1,
+            Opcode::Status => 2,
+            Opcode::Notify => 4,
+            Opcode::Update => 5,
+            Opcode::DNSStatefulOperations => 6,
+            Opcode::Unassigned => 3,
+        };
+        bytes.push(
+            (query_response << 7)
+                | (operation_code << 3)
+                | ((self.authoritative_answer as u8) << 2)
+                | ((self.truncated_message as u8) << 1)
+                | (self.recursion_desired as u8),
+        );
+
+        let response_code = match &self.response_code {
+            Ok(()) => 0,
+            Err(RCode::FormErr) => 1,
+            Err(RCode::ServFail) => 2,
+            Err(RCode::NXDomain) => 3,
+            Err(RCode::NotImp) => 4,
+            Err(RCode::Refused) => 5,
+            Err(RCode::YXDomain) => 6,
+            Err(RCode::YXRRSet) => 7,
+            Err(RCode::NXRRSet) => 8,
+            Err(RCode::NotAuth) => 9,
+            Err(RCode::NotZone) => 10,
+            Err(RCode::DSOTYPENI) => 11,
+            Err(RCode::Unassigned) => 12,
+        };
+        bytes.push(((self.recursion_available as u8) << 7) | response_code);
+
+        bytes.extend_from_slice(&self.question_count.to_be_bytes());
+        bytes.extend_from_slice(&self.answer_count.to_be_bytes());
+        bytes.extend_from_slice(&self.authority_count.to_be_bytes());
+        bytes.extend_from_slice(&self.additional_count.to_be_bytes());
+
+        for question in &self.questions {
+            bytes.extend(question.to_bytes());
+        }
+        for record in self
+            .answers
+            .iter()
+            .chain(self.authorities.iter())
+            .chain(self.additionals.iter())
+        {
+            bytes.extend(record.to_bytes());
+        }
+
+        bytes
+    }
+}
+
+impl From<&DnsPacket> for Vec<u8> {
+    fn from(packet: &DnsPacket) -> Self {
+        packet.to_bytes()
+    }
+}
+
 impl TryFrom<(&[u8], &mut u16)> for Question {
     type Error = DnsPacketParseError;
 
     fn try_from((value, position): (&[u8], &mut u16)) -> Result<Self, Self::Error> {
-        let mut name = String::new();
-        for label in LabelSequenceIterator::new(position, value) {
-            if !name.is_empty() {
-                name.push('.');
-            }
-            name.extend(label?.iter().map(|&b| char::from(b)));
-        }
-        let class = match get16(*position as usize + 2, value)? {
-            1 => Class::Internet,
-            _ => Class::Other,
-        };
-        let r#type = match get16(*position as usize, value)? {
-            1 => RecordType::AddressRecord,
-            _ => RecordType::Other,
-        };
+        let name = read_name(position, value)?;
+        let class = Class::from(get16(*position as usize + 2, value)?);
+        let r#type = RecordType::from(get16(*position as usize, value)?);
         *position += 4;
         Ok(Question {
             name,
@@ -268,20 +661,60 @@ impl TryFrom<(&[u8], &mut u16)> for Question {
         })
     }
 }
+
+impl Question {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = encode_name(&self.name);
+        bytes.extend_from_slice(&self.r#type.to_u16().to_be_bytes());
+        bytes.extend_from_slice(&self.class.to_u16().to_be_bytes());
+        bytes
+    }
+
+    pub fn display_name(&self) -> String {
+        idna::domain_to_unicode(&self.name).0
+    }
+}
 impl TryFrom<(&[u8], &mut u16)> for RecordPreamble {
     type Error = DnsPacketParseError;
 
     fn try_from((value, position): (&[u8], &mut u16)) -> Result<Self, Self::Error> {
         let question = Question::try_from((value, &mut *position))?;
+        let record_type_code = question.r#type.to_u16();
         let time_to_live = get32((*position) as usize, value)?;
         *position += 4;
+        let rdlength = get16((*position) as usize, value)?;
+        *position += 2;
+        let rdata_start = *position;
+        let rdata_end = rdata_start as usize + rdlength as usize;
+        let rdata = value
+            .get(rdata_start as usize..rdata_end)
+            .ok_or_else(|| DnsPacketParseError::OutOfBounds {
+                index: rdata_end,
+                length: value.len(),
+            })?;
+        let data = RecordData::parse(record_type_code, rdata_start, rdata, value)?;
+        *position += rdlength;
         Ok(Self {
             question,
             time_to_live,
+            data,
         })
     }
 }
 
+impl RecordPreamble {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = encode_name(&self.question.name);
+        bytes.extend_from_slice(&self.question.r#type.to_u16().to_be_bytes());
+        bytes.extend_from_slice(&self.question.class.to_u16().to_be_bytes());
+        bytes.extend_from_slice(&self.time_to_live.to_be_bytes());
+        let rdata = self.data.to_bytes();
+        bytes.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        bytes.extend(rdata);
+        bytes
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -459,13 +892,40 @@ mod tests {
         assert_eq!(position, 30);
     }
 
+    #[test]
+    fn label_sequence_rejects_self_referential_pointer() {
+        let packet = [0b1100_0000, 0b0000_0000];
+        let mut position = 0;
+        let i = LabelSequenceIterator::new(&mut position, &packet);
+        assert_eq!(
+            i.collect::<Result<Vec<_>, _>>(),
+            Err(DnsPacketParseError::InvalidPointer { pointer: 0 })
+        );
+    }
+
+    #[test]
+    fn label_sequence_rejects_oversized_name() {
+        let mut packet = Vec::new();
+        for _ in 0..5 {
+            packet.push(63);
+            packet.extend(std::iter::repeat(b'a').take(63));
+        }
+        packet.push(0);
+        let mut position = 0;
+        let i = LabelSequenceIterator::new(&mut position, &packet);
+        assert_eq!(
+            i.collect::<Result<Vec<_>, _>>(),
+            Err(DnsPacketParseError::NameTooLong)
+        );
+    }
+
     #[test]
     fn questions() {
         assert_eq!(
             DnsPacket::try_from(QUERY_PACKET).unwrap().questions,
             vec![Question {
                 name: "google.com".to_string(),
-                r#type: RecordType::AddressRecord,
+                r#type: RecordType::A,
                 class: Class::Internet
             }]
         );
@@ -473,7 +933,7 @@ mod tests {
             DnsPacket::try_from(RESPONSE_PACKET).unwrap().questions,
             vec![Question {
                 name: "google.com".to_string(),
-                r#type: RecordType::AddressRecord,
+                r#type: RecordType::A,
                 class: Class::Internet
             }]
         );
@@ -486,11 +946,100 @@ mod tests {
             vec![RecordPreamble {
                 question: Question {
                     name: "google.com".to_string(),
-                    r#type: RecordType::AddressRecord,
+                    r#type: RecordType::A,
                     class: Class::Internet
                 },
-                time_to_live: 264
+                time_to_live: 264,
+                data: RecordData::A(Ipv4Addr::new(172, 217, 16, 142)),
             }]
         );
     }
+
+    #[test]
+    fn authorities() {
+        assert_eq!(
+            DnsPacket::try_from(QUERY_PACKET).unwrap().authorities,
+            vec![]
+        );
+        assert_eq!(
+            DnsPacket::try_from(RESPONSE_PACKET).unwrap().authorities,
+            vec![]
+        );
+    }
+
+    #[test]
+    fn additionals() {
+        assert_eq!(
+            DnsPacket::try_from(QUERY_PACKET).unwrap().additionals,
+            vec![]
+        );
+        assert_eq!(
+            DnsPacket::try_from(RESPONSE_PACKET).unwrap().additionals,
+            vec![]
+        );
+    }
+
+    #[test]
+    fn edns() {
+        let packet: &[u8] = &[
+            0x00, 0x00, // id
+            0x81, 0x00, // flags: QR=1, RD=1
+            0x00, 0x00, // qdcount
+            0x00, 0x00, // ancount
+            0x00, 0x00, // nscount
+            0x00, 0x01, // arcount
+            0x00, // root name
+            0x00, 0x29, // type OPT
+            0x10, 0x00, // udp payload size 4096
+            0x00, 0x00, 0x80, 0x00, // extended rcode 0, version 0, DO set
+            0x00, 0x00, // rdlength
+        ];
+        let dns_packet = DnsPacket::try_from(packet).unwrap();
+        assert_eq!(
+            dns_packet.edns,
+            Some(Edns {
+                udp_payload_size: 4096,
+                extended_rcode: 0,
+                version: 0,
+                dnssec_ok: true,
+                options: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let query = DnsPacket::try_from(QUERY_PACKET).unwrap();
+        assert_eq!(DnsPacket::try_from(query.to_bytes().as_slice()), Ok(query));
+
+        let response = DnsPacket::try_from(RESPONSE_PACKET).unwrap();
+        assert_eq!(
+            DnsPacket::try_from(response.to_bytes().as_slice()),
+            Ok(response)
+        );
+    }
+
+    #[test]
+    fn record_type_round_trip() {
+        for code in 0..=u16::MAX {
+            assert_eq!(RecordType::from(code).to_u16(), code);
+        }
+    }
+
+    #[test]
+    fn record_type_unknown_retains_code() {
+        assert_eq!(RecordType::from(999), RecordType::Unknown(999));
+    }
+
+    #[test]
+    fn class_round_trip() {
+        for code in 0..=u16::MAX {
+            assert_eq!(Class::from(code).to_u16(), code);
+        }
+    }
+
+    #[test]
+    fn class_unknown_retains_code() {
+        assert_eq!(Class::from(999), Class::Unknown(999));
+    }
 }
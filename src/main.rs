@@ -1,6 +1,13 @@
 use color_eyre::eyre::Result;
+use dns_packet::Class;
 use dns_packet::DnsPacket;
 use dns_packet::DnsPacketParseError;
+use dns_packet::Opcode;
+use dns_packet::QueryResponse;
+use dns_packet::Question;
+use dns_packet::RecordData;
+use dns_packet::RecordPreamble;
+use dns_packet::RecordType;
 use pretty_hex::PrettyHex;
 use std::convert::TryFrom;
 use std::net::Ipv4Addr;
@@ -10,12 +17,16 @@ use std::net::UdpSocket;
 use structopt::StructOpt;
 use thiserror::Error;
 
+const REQUESTED_UDP_PAYLOAD_SIZE: u16 = 4096;
+
 #[derive(Error, Debug)]
 enum MainError {
     #[error("label {label:} incorrect size")]
     LabelSize { label: String },
-    #[error("label {label:} contains invalid characters")]
-    LabelInvalidCharacter { label: String },
+    #[error("host name is not a valid internationalized domain name: {0:?}")]
+    InvalidHostName(idna::Errors),
+    #[error("host name exceeds 255 bytes once IDNA encoded")]
+    HostNameTooLong,
     #[error("Error during bind")]
     BindError(#[source] std::io::Error),
     #[error("Error during send")]
@@ -36,52 +47,60 @@ struct Opt {
     resolver: Ipv4Addr,
 }
 
-fn main() -> Result<()> {
-    color_eyre::install()?;
-    let opt = Opt::from_args();
-    let mut request_packet = Vec::new();
-    // Header ID
-    request_packet.extend_from_slice(&[0, 0]);
-    // Header QR, Opcode, AA, TC and RD
-    request_packet.push(0b0000_0001);
-    // Header RA, Z, RCODE
-    request_packet.push(0b0000_0000);
-    // Header QDCOUNT
-    request_packet.extend_from_slice(&[0, 1]);
-    // Header ANCOUNT
-    request_packet.extend_from_slice(&[0, 0]);
-    // Header NSCOUNT
-    request_packet.extend_from_slice(&[0, 0]);
-    // Header ARCOUNT
-    request_packet.extend_from_slice(&[0, 0]);
-
-    // The question Label Sequence
-    for label in opt.host_name.split('.') {
-        let length = u8::try_from(label.len())
-            .ok()
-            .and_then(|l| if l == 0 || l > 63 { None } else { Some(l) })
-            .ok_or_else(|| MainError::LabelSize {
+fn ascii_host_name(host_name: &str) -> Result<String, MainError> {
+    let ascii_host_name = idna::domain_to_ascii(host_name).map_err(MainError::InvalidHostName)?;
+    if ascii_host_name.len() > 255 {
+        return Err(MainError::HostNameTooLong);
+    }
+    for label in ascii_host_name.split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(MainError::LabelSize {
                 label: label.to_string(),
-            })?;
-        request_packet.push(length);
-        for byte in label.bytes() {
-            match byte {
-                b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' => request_packet.push(byte),
-                _ => {
-                    return Err(MainError::LabelInvalidCharacter {
-                        label: label.to_string(),
-                    }
-                    .into())
-                }
-            }
+            });
         }
     }
-    request_packet.push(0);
+    Ok(ascii_host_name)
+}
 
-    // The question Type
-    request_packet.extend_from_slice(&[0, 1]);
-    // The question Class
-    request_packet.extend_from_slice(&[0, 1]);
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let opt = Opt::from_args();
+    let ascii_host_name = ascii_host_name(&opt.host_name)?;
+
+    let edns_record = RecordPreamble {
+        question: Question {
+            name: String::new(),
+            r#type: RecordType::Opt,
+            class: Class::Unknown(REQUESTED_UDP_PAYLOAD_SIZE),
+        },
+        time_to_live: 0,
+        data: RecordData::Unknown(Vec::new()),
+    };
+
+    let request = DnsPacket {
+        packet_identifier: 0,
+        query_response: QueryResponse::Query,
+        operation_code: Opcode::Query,
+        authoritative_answer: false,
+        truncated_message: false,
+        recursion_desired: true,
+        recursion_available: false,
+        response_code: Ok(()),
+        question_count: 1,
+        answer_count: 0,
+        authority_count: 0,
+        additional_count: 1,
+        questions: vec![Question {
+            name: ascii_host_name,
+            r#type: RecordType::A,
+            class: Class::Internet,
+        }],
+        answers: vec![],
+        authorities: vec![],
+        additionals: vec![edns_record],
+        edns: None,
+    };
+    let request_packet = request.to_bytes();
 
     println!("request packet: {:?}", request_packet.hex_dump());
 
@@ -94,7 +113,7 @@ fn main() -> Result<()> {
         .send_to(&request_packet, resolver_address)
         .map_err(MainError::SendError)?;
 
-    let mut buf = [0; 512];
+    let mut buf = [0; REQUESTED_UDP_PAYLOAD_SIZE as usize];
     let (number_of_bytes_received, src_addr) = socket
         .recv_from(&mut buf)
         .map_err(MainError::ReceiveError)?;
@@ -110,7 +129,13 @@ fn main() -> Result<()> {
 
     let dns_packet = DnsPacket::try_from(response_packet).map_err(MainError::InvalidDnsPacket)?;
 
+    for question in &dns_packet.questions {
+        println!("\nquestion: {:?}", question.display_name());
+    }
     println!("\n{:?}", dns_packet.answers);
+    if let Some(edns) = &dns_packet.edns {
+        println!("\nedns: {:?}", edns);
+    }
 
     Ok(())
 }